@@ -0,0 +1,109 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::chip8::INITIAL_PC;
+
+/// Decodes a single fetched opcode into an assembly mnemonic, using the same
+/// nibble decomposition as `Chip8::execute`. Anything that doesn't match a
+/// known opcode is rendered as a `DB` data directive rather than being
+/// silently skipped.
+pub fn disassemble(instruction: u16) -> String {
+    let b0 = (instruction & 0xFF00) >> 8;
+    let b1 = (instruction & 0x00FF) as u8;
+
+    let opcode = (b0 & 0xF0) >> 4;
+    let x = (b0 & 0x0F) as usize;
+    let y = ((b1 & 0xF0) >> 4) as usize;
+    let n = b1 & 0x0F;
+    let byte = b1;
+    let nnn = instruction & 0x0FFF;
+
+    match (opcode, x, y, n) {
+        (0x0, 0x0, 0xC, _) => format!("SCD {n:#X}"),
+        (0x0, 0x0, 0xE, 0x0) => "CLS".into(),
+        (0x0, 0x0, 0xE, 0xE) => "RET".into(),
+        (0x0, 0x0, 0xF, 0xB) => "SCR".into(),
+        (0x0, 0x0, 0xF, 0xC) => "SCL".into(),
+        (0x0, 0x0, 0xF, 0xD) => "EXIT".into(),
+        (0x0, 0x0, 0xF, 0xE) => "LOW".into(),
+        (0x0, 0x0, 0xF, 0xF) => "HIGH".into(),
+        (0x1, _, _, _) => format!("JP {nnn:#05X}"),
+        (0x2, _, _, _) => format!("CALL {nnn:#05X}"),
+        (0x3, _, _, _) => format!("SE V{x:X}, {byte:#04X}"),
+        (0x4, _, _, _) => format!("SNE V{x:X}, {byte:#04X}"),
+        (0x5, _, _, 0x0) => format!("SE V{x:X}, V{y:X}"),
+        (0x6, _, _, _) => format!("LD V{x:X}, {byte:#04X}"),
+        (0x7, _, _, _) => format!("ADD V{x:X}, {byte:#04X}"),
+        (0x8, _, _, 0x0) => format!("LD V{x:X}, V{y:X}"),
+        (0x8, _, _, 0x1) => format!("OR V{x:X}, V{y:X}"),
+        (0x8, _, _, 0x2) => format!("AND V{x:X}, V{y:X}"),
+        (0x8, _, _, 0x3) => format!("XOR V{x:X}, V{y:X}"),
+        (0x8, _, _, 0x4) => format!("ADD V{x:X}, V{y:X}"),
+        (0x8, _, _, 0x5) => format!("SUB V{x:X}, V{y:X}"),
+        (0x8, _, _, 0x6) => format!("SHR V{x:X} {{, V{y:X}}}"),
+        (0x8, _, _, 0x7) => format!("SUBN V{x:X}, V{y:X}"),
+        (0x8, _, _, 0xE) => format!("SHL V{x:X} {{, V{y:X}}}"),
+        (0x9, _, _, 0x0) => format!("SNE V{x:X}, V{y:X}"),
+        (0xA, _, _, _) => format!("LD I, {nnn:#05X}"),
+        (0xB, _, _, _) => format!("JP V0, {nnn:#05X}"),
+        (0xC, _, _, _) => format!("RND V{x:X}, {byte:#04X}"),
+        (0xD, _, _, _) => format!("DRW V{x:X}, V{y:X}, {n:#X}"),
+        (0xE, _, 0x9, 0xE) => format!("SKP V{x:X}"),
+        (0xE, _, 0xA, 0x1) => format!("SKNP V{x:X}"),
+        (0xF, _, 0x0, 0x7) => format!("LD V{x:X}, DT"),
+        (0xF, _, 0x0, 0xA) => format!("LD V{x:X}, K"),
+        (0xF, _, 0x1, 0x5) => format!("LD DT, V{x:X}"),
+        (0xF, _, 0x1, 0x8) => format!("LD ST, V{x:X}"),
+        (0xF, _, 0x1, 0xE) => format!("ADD I, V{x:X}"),
+        (0xF, _, 0x2, 0x9) => format!("LD F, V{x:X}"),
+        (0xF, _, 0x3, 0x0) => format!("LD HF, V{x:X}"),
+        (0xF, _, 0x3, 0x3) => format!("LD B, V{x:X}"),
+        (0xF, _, 0x5, 0x5) => format!("LD [I], V{x:X}"),
+        (0xF, _, 0x6, 0x5) => format!("LD V{x:X}, [I]"),
+        (0xF, _, 0x7, 0x5) => format!("LD R, V{x:X}"),
+        (0xF, _, 0x8, 0x5) => format!("LD V{x:X}, R"),
+        _ => format!("DB {instruction:#06X}"),
+    }
+}
+
+/// Produces a full static listing of a ROM file, one entry per two-byte
+/// instruction starting at the usual load address (`0x200`).
+pub fn disassemble_rom(path: &Path) -> io::Result<Vec<(u16, String)>> {
+    let bytes = fs::read(path)?;
+    let mut listing = Vec::with_capacity(bytes.len() / 2);
+
+    let mut offset = 0;
+    while offset + 1 < bytes.len() {
+        let addr = INITIAL_PC + offset as u16;
+        let instruction = (bytes[offset] as u16) << 8 | bytes[offset + 1] as u16;
+        listing.push((addr, disassemble(instruction)));
+        offset += 2;
+    }
+
+    if offset < bytes.len() {
+        let addr = INITIAL_PC + offset as u16;
+        listing.push((addr, format!("DB {:#04X}", bytes[offset])));
+    }
+
+    Ok(listing)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn disassembles_known_opcodes() {
+        assert_eq!(disassemble(0x00E0), "CLS");
+        assert_eq!(disassemble(0x1234), "JP 0x234");
+        assert_eq!(disassemble(0x6A05), "LD VA, 0x05");
+        assert_eq!(disassemble(0xD125), "DRW V1, V2, 0x5");
+        assert_eq!(disassemble(0xF055), "LD [I], V0");
+    }
+
+    #[test]
+    fn falls_back_to_db_for_unknown_opcodes() {
+        assert_eq!(disassemble(0x5001), "DB 0x5001");
+    }
+}
@@ -0,0 +1,31 @@
+pub const LOW_WIDTH: u8 = 64;
+pub const LOW_HEIGHT: u8 = 32;
+pub const HIGH_WIDTH: u8 = 128;
+pub const HIGH_HEIGHT: u8 = 64;
+
+/// The two screen resolutions a SUPER-CHIP interpreter can switch between at
+/// runtime via `00FE`/`00FF`. `Chip8::display` is always sized for
+/// [`HIGH_WIDTH`]x[`HIGH_HEIGHT`]; the current resolution just determines how
+/// much of that buffer is addressable and drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Resolution {
+    #[default]
+    Low,
+    High,
+}
+
+impl Resolution {
+    pub fn width(&self) -> u8 {
+        match self {
+            Resolution::Low => LOW_WIDTH,
+            Resolution::High => HIGH_WIDTH,
+        }
+    }
+
+    pub fn height(&self) -> u8 {
+        match self {
+            Resolution::Low => LOW_HEIGHT,
+            Resolution::High => HIGH_HEIGHT,
+        }
+    }
+}
@@ -7,22 +7,29 @@ use std::{
     path::Path,
 };
 
+use display::Resolution;
 use keypad::Keypad;
+use quirks::Quirks;
+use state::Chip8State;
 use tracing::error;
 
+pub mod disasm;
 pub mod display;
 mod font;
 pub mod keypad;
+pub mod quirks;
+pub mod state;
 
-const INITIAL_PC: u16 = 0x200;
-const MEMORY_SIZE: usize = 4096;
+pub(crate) const INITIAL_PC: u16 = 0x200;
+pub(crate) const MEMORY_SIZE: usize = 4096;
 const MAX_ROM_SIZE: usize = MEMORY_SIZE - INITIAL_PC as usize;
 
 type Instruction = u16;
 
 pub struct Chip8 {
     memory: [u8; MEMORY_SIZE],
-    pub display: [[bool; display::HEIGHT as usize]; display::WIDTH as usize],
+    pub display: [[bool; display::HIGH_HEIGHT as usize]; display::HIGH_WIDTH as usize],
+    resolution: Resolution,
     pc: u16,
     i: u16,
     stack: [u16; 16],
@@ -30,14 +37,20 @@ pub struct Chip8 {
     delay_timer: u8,
     sound_timer: u8,
     v: [u8; 16], // registers
+    rpl: [u8; 8],
     pub keypad: Keypad,
+    pub quirks: Quirks,
+    /// Set by the SUPER-CHIP `00FD` (EXIT) opcode; the host is expected to
+    /// stop ticking once this is true.
+    pub exited: bool,
 }
 
 impl Chip8 {
-    pub fn new() -> Chip8 {
+    pub fn with_quirks(quirks: Quirks) -> Chip8 {
         let mut c = Chip8 {
             memory: [0; 4096],
-            display: [[false; display::HEIGHT as usize]; display::WIDTH as usize],
+            display: [[false; display::HIGH_HEIGHT as usize]; display::HIGH_WIDTH as usize],
+            resolution: Resolution::default(),
             pc: INITIAL_PC,
             i: 0,
             stack: [0; 16],
@@ -45,7 +58,10 @@ impl Chip8 {
             delay_timer: 0,
             sound_timer: 0,
             v: [0; 16],
+            rpl: [0; 8],
             keypad: Keypad::new(),
+            quirks,
+            exited: false,
         };
 
         font::load_fonts(&mut c.memory);
@@ -53,6 +69,74 @@ impl Chip8 {
         c
     }
 
+    /// The width, in pixels, of the current resolution mode.
+    pub fn width(&self) -> u8 {
+        self.resolution.width()
+    }
+
+    /// The height, in pixels, of the current resolution mode.
+    pub fn height(&self) -> u8 {
+        self.resolution.height()
+    }
+
+    pub fn pc(&self) -> u16 {
+        self.pc
+    }
+
+    pub fn i(&self) -> u16 {
+        self.i
+    }
+
+    pub fn v(&self) -> &[u8; 16] {
+        &self.v
+    }
+
+    pub fn memory(&self) -> &[u8] {
+        &self.memory
+    }
+
+    pub fn stack(&self) -> &[u16; 16] {
+        &self.stack
+    }
+
+    pub fn sp(&self) -> u8 {
+        self.sp
+    }
+
+    /// Captures the entire machine state as a snapshot that can later be
+    /// restored with [`Chip8::load_state`].
+    pub fn save_state(&self) -> Chip8State {
+        Chip8State {
+            memory: self.memory,
+            display: self.display,
+            resolution: self.resolution,
+            pc: self.pc,
+            i: self.i,
+            stack: self.stack,
+            sp: self.sp,
+            delay_timer: self.delay_timer,
+            sound_timer: self.sound_timer,
+            v: self.v,
+            rpl: self.rpl,
+        }
+    }
+
+    /// Restores the entire machine state from a snapshot taken by
+    /// [`Chip8::save_state`].
+    pub fn load_state(&mut self, state: &Chip8State) {
+        self.memory = state.memory;
+        self.display = state.display;
+        self.resolution = state.resolution;
+        self.pc = state.pc;
+        self.i = state.i;
+        self.stack = state.stack;
+        self.sp = state.sp;
+        self.delay_timer = state.delay_timer;
+        self.sound_timer = state.sound_timer;
+        self.v = state.v;
+        self.rpl = state.rpl;
+    }
+
     pub fn load_rom(&mut self, file: &Path) -> Result<(), Box<dyn Error>> {
         let metadata = std::fs::metadata(file)?;
         let file_size = metadata.len() as usize;
@@ -95,6 +179,11 @@ impl Chip8 {
         (self.memory[self.pc as usize] as u16) << 8 | (self.memory[self.pc as usize + 1] as u16)
     }
 
+    /// Disassembles the instruction the program counter is about to execute.
+    pub fn disassemble_current(&self) -> String {
+        disasm::disassemble(self.current_instruction())
+    }
+
     fn fetch(&mut self) -> Instruction {
         let next_instruction = self.current_instruction();
         self.pc += 2;
@@ -113,12 +202,14 @@ impl Chip8 {
         let nnn = instruction & 0x0FFF;
 
         match (opcode, x, y, n) {
+            // SCD n - scroll display down n pixels
+            (0x0, 0x0, 0xC, _) => {
+                self.scroll_down(n as usize);
+            }
             // CLS
             (0x0, 0x0, 0xE, 0x0) => {
-                for y in 0..display::HEIGHT {
-                    for x in 0..display::WIDTH {
-                        self.display[x as usize][y as usize] = false;
-                    }
+                for row in self.display.iter_mut() {
+                    row.fill(false);
                 }
             }
             // RET
@@ -126,6 +217,26 @@ impl Chip8 {
                 self.pc = self.stack[self.sp as usize];
                 self.sp -= 1;
             }
+            // SCR - scroll display right 4 pixels
+            (0x0, 0x0, 0xF, 0xB) => {
+                self.scroll_right();
+            }
+            // SCL - scroll display left 4 pixels
+            (0x0, 0x0, 0xF, 0xC) => {
+                self.scroll_left();
+            }
+            // EXIT
+            (0x0, 0x0, 0xF, 0xD) => {
+                self.exited = true;
+            }
+            // LOW - switch to 64x32 mode
+            (0x0, 0x0, 0xF, 0xE) => {
+                self.resolution = Resolution::Low;
+            }
+            // HIGH - switch to 128x64 mode
+            (0x0, 0x0, 0xF, 0xF) => {
+                self.resolution = Resolution::High;
+            }
             // JP addr
             (0x1, _, _, _) => {
                 self.pc = nnn;
@@ -169,14 +280,23 @@ impl Chip8 {
             // OR Vx, Vy
             (0x8, _, _, 0x1) => {
                 self.v[x] |= self.v[y];
+                if self.quirks.vf_reset {
+                    self.v[0xF] = 0;
+                }
             }
             // AND Vx, Vy
             (0x8, _, _, 0x2) => {
                 self.v[x] &= self.v[y];
+                if self.quirks.vf_reset {
+                    self.v[0xF] = 0;
+                }
             }
             // XOR Vx, Vy
             (0x8, _, _, 0x3) => {
                 self.v[x] ^= self.v[y];
+                if self.quirks.vf_reset {
+                    self.v[0xF] = 0;
+                }
             }
             // ADD Vx, Vy
             (0x8, _, _, 0x4) => {
@@ -192,6 +312,9 @@ impl Chip8 {
             }
             // SHR Vx {, Vy}
             (0x8, _, _, 0x6) => {
+                if self.quirks.shift {
+                    self.v[x] = self.v[y];
+                }
                 let lsb = self.v[x] & 1;
                 self.v[x] >>= 1;
                 self.v[0xF] = lsb;
@@ -204,6 +327,9 @@ impl Chip8 {
             }
             // SHL Vx {, Vy}
             (0x8, _, _, 0xE) => {
+                if self.quirks.shift {
+                    self.v[x] = self.v[y];
+                }
                 let msb = (self.v[x] >> 7) & 1;
                 self.v[x] <<= 1;
                 self.v[0xF] = msb;
@@ -220,23 +346,39 @@ impl Chip8 {
             }
             // JP V0, addr
             (0xB, _, _, _) => {
-                self.pc = nnn + self.v[0] as u16;
+                let v = if self.quirks.jump { self.v[x] } else { self.v[0] };
+                self.pc = nnn + v as u16;
             }
             // RND Vx, byte
             (0xC, _, _, _) => {
                 let rand = rand::random::<u8>();
                 self.v[x] = rand & byte;
             }
-            // DRW Vx, Vy, nibble
+            // DRW Vx, Vy, nibble (nibble == 0 draws a 16x16 SUPER-CHIP sprite)
             (0xD, _, _, _) => {
                 self.v[0x0f] = 0;
-                for byte in 0..n {
-                    let y = (self.v[y] as usize + byte as usize) % display::HEIGHT as usize;
-                    for bit in 0..8 {
-                        let x = (self.v[x] as usize + bit) % display::WIDTH as usize;
-                        let color = (self.memory[self.i as usize + byte as usize] >> (7 - bit)) & 1;
-                        self.v[0x0f] |= color & self.display[x][y] as u8;
-                        self.display[x][y] ^= color != 0;
+                let (rows, bytes_per_row) = if n == 0 { (16, 2) } else { (n as usize, 1) };
+                let width = self.width() as usize;
+                let height = self.height() as usize;
+
+                for row in 0..rows {
+                    let raw_y = self.v[y] as usize + row;
+                    if self.quirks.clipping && raw_y >= height {
+                        continue;
+                    }
+                    let py = raw_y % height;
+
+                    for col in 0..(bytes_per_row * 8) {
+                        let raw_x = self.v[x] as usize + col;
+                        if self.quirks.clipping && raw_x >= width {
+                            continue;
+                        }
+                        let px = raw_x % width;
+
+                        let byte = self.memory[self.i as usize + row * bytes_per_row + col / 8];
+                        let color = (byte >> (7 - (col % 8))) & 1;
+                        self.v[0x0f] |= color & self.display[px][py] as u8;
+                        self.display[px][py] ^= color != 0;
                     }
                 }
             }
@@ -281,6 +423,11 @@ impl Chip8 {
                 let sprite = self.v[x];
                 self.i = font::get_sprite_addr(sprite);
             }
+            // LD HF, Vx - point I at the 8x10 large hex font sprite
+            (0xF, _, 0x3, 0x0) => {
+                let sprite = self.v[x];
+                self.i = font::get_large_sprite_addr(sprite);
+            }
             // LD B, Vx
             (0xF, _, 0x3, 0x3) => {
                 let val = self.v[x];
@@ -293,15 +440,69 @@ impl Chip8 {
                 for i in 0..=x {
                     self.memory[self.i as usize + i] = self.v[i];
                 }
+                if self.quirks.load_store {
+                    self.i += x as u16 + 1;
+                }
             }
             // LD Vx, [I]
             (0xF, _, 0x6, 0x5) => {
                 for i in 0..=x {
                     self.v[i] = self.memory[self.i as usize + i];
                 }
+                if self.quirks.load_store {
+                    self.i += x as u16 + 1;
+                }
+            }
+            // LD R, Vx - save V0..Vx to the RPL flag registers
+            (0xF, _, 0x7, 0x5) => {
+                for i in 0..=x.min(self.rpl.len() - 1) {
+                    self.rpl[i] = self.v[i];
+                }
+            }
+            // LD Vx, R - restore V0..Vx from the RPL flag registers
+            (0xF, _, 0x8, 0x5) => {
+                for i in 0..=x.min(self.rpl.len() - 1) {
+                    self.v[i] = self.rpl[i];
+                }
             }
             _ => {
-                error!("Unknown instruction: {:#06X}", instruction);
+                error!(
+                    "Unknown instruction, treating as data: {}",
+                    disasm::disassemble(instruction)
+                );
+            }
+        }
+    }
+
+    fn scroll_down(&mut self, n: usize) {
+        let width = self.width() as usize;
+        let height = self.height() as usize;
+
+        for x in 0..width {
+            for y in (0..height).rev() {
+                self.display[x][y] = y.checked_sub(n).is_some_and(|src| self.display[x][src]);
+            }
+        }
+    }
+
+    fn scroll_right(&mut self) {
+        let width = self.width() as usize;
+        let height = self.height() as usize;
+
+        for y in 0..height {
+            for x in (0..width).rev() {
+                self.display[x][y] = x.checked_sub(4).is_some_and(|src| self.display[src][y]);
+            }
+        }
+    }
+
+    fn scroll_left(&mut self) {
+        let width = self.width() as usize;
+        let height = self.height() as usize;
+
+        for y in 0..height {
+            for x in 0..width {
+                self.display[x][y] = x + 4 < width && self.display[x + 4][y];
             }
         }
     }
@@ -0,0 +1,88 @@
+/// Toggles for the historically-ambiguous CHIP-8 opcodes, so a ROM can be
+/// run against the interpretation it was actually written for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Quirks {
+    /// `8XY6`/`8XYE`: when set, `Vx` is first loaded from `Vy` before being
+    /// shifted. When unset, `Vx` is shifted in place and `Vy` is ignored.
+    pub shift: bool,
+    /// `FX55`/`FX65`: when set, `I` is left incremented by `x + 1` after the
+    /// load/store completes. When unset, `I` is unchanged.
+    pub load_store: bool,
+    /// `BNNN`: when set, jumps to `VX + nnn` where `X` is the instruction's
+    /// high nibble. When unset, jumps to `V0 + nnn`.
+    pub jump: bool,
+    /// `8XY1`/`8XY2`/`8XY3`: when set, OR/AND/XOR reset `VF` to 0.
+    pub vf_reset: bool,
+    /// `DXYN`: when set, sprites are clipped at the screen edge instead of
+    /// wrapping around to the opposite side.
+    pub clipping: bool,
+}
+
+impl Quirks {
+    /// Matches the behaviour this interpreter shipped with before `Quirks`
+    /// existed: `Vx` shifted in place, `I` left unchanged by `FX55`/`FX65`,
+    /// `VF` untouched by OR/AND/XOR, and sprites wrapping at the screen
+    /// edge. Note this is *not* authentic COSMAC VIP behaviour on any of
+    /// those points — it's a compatibility default for ROMs already tuned
+    /// against this interpreter, not a historical-accuracy preset.
+    pub fn chip8() -> Quirks {
+        Quirks {
+            shift: false,
+            load_store: false,
+            jump: false,
+            vf_reset: false,
+            clipping: false,
+        }
+    }
+
+    /// SUPER-CHIP 1.1 behaviour, as implemented by most SCHIP-compatible
+    /// interpreters.
+    pub fn superchip() -> Quirks {
+        Quirks {
+            shift: false,
+            load_store: false,
+            jump: true,
+            vf_reset: false,
+            clipping: true,
+        }
+    }
+
+    /// XO-CHIP behaviour.
+    pub fn xochip() -> Quirks {
+        Quirks {
+            shift: false,
+            load_store: true,
+            jump: false,
+            vf_reset: false,
+            clipping: false,
+        }
+    }
+}
+
+impl Default for Quirks {
+    fn default() -> Self {
+        Quirks::chip8()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_matches_chip8_preset() {
+        assert_eq!(Quirks::default(), Quirks::chip8());
+    }
+
+    #[test]
+    fn superchip_uses_vx_based_jump() {
+        assert!(Quirks::superchip().jump);
+        assert!(!Quirks::chip8().jump);
+    }
+
+    #[test]
+    fn xochip_does_not_clip_sprites() {
+        assert!(!Quirks::xochip().clipping);
+        assert!(Quirks::superchip().clipping);
+    }
+}
@@ -0,0 +1,159 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::chip8::{display, display::Resolution, MEMORY_SIZE};
+
+/// A point-in-time snapshot of a [`Chip8`](crate::chip8::Chip8)'s entire
+/// machine state, suitable for a save/load-game style checkpoint.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Chip8State {
+    pub(crate) memory: [u8; MEMORY_SIZE],
+    pub(crate) display: [[bool; display::HIGH_HEIGHT as usize]; display::HIGH_WIDTH as usize],
+    pub(crate) resolution: Resolution,
+    pub(crate) pc: u16,
+    pub(crate) i: u16,
+    pub(crate) stack: [u16; 16],
+    pub(crate) sp: u8,
+    pub(crate) delay_timer: u8,
+    pub(crate) sound_timer: u8,
+    pub(crate) v: [u8; 16],
+    pub(crate) rpl: [u8; 8],
+}
+
+impl Chip8State {
+    /// Packs the snapshot into a compact binary blob.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(MEMORY_SIZE + self.display.len() * self.display[0].len() + 32);
+
+        buf.extend_from_slice(&self.memory);
+        for column in &self.display {
+            buf.extend(column.iter().map(|&pixel| pixel as u8));
+        }
+        buf.push(match self.resolution {
+            Resolution::Low => 0,
+            Resolution::High => 1,
+        });
+        buf.extend_from_slice(&self.pc.to_le_bytes());
+        buf.extend_from_slice(&self.i.to_le_bytes());
+        for addr in self.stack {
+            buf.extend_from_slice(&addr.to_le_bytes());
+        }
+        buf.push(self.sp);
+        buf.push(self.delay_timer);
+        buf.push(self.sound_timer);
+        buf.extend_from_slice(&self.v);
+        buf.extend_from_slice(&self.rpl);
+
+        buf
+    }
+
+    /// Unpacks a snapshot previously produced by [`Chip8State::to_bytes`].
+    /// Returns `None` if `bytes` is truncated or otherwise malformed.
+    pub fn from_bytes(bytes: &[u8]) -> Option<Chip8State> {
+        let mut offset = 0;
+
+        let memory: [u8; MEMORY_SIZE] = bytes.get(offset..offset + MEMORY_SIZE)?.try_into().ok()?;
+        offset += MEMORY_SIZE;
+
+        let mut display = [[false; display::HIGH_HEIGHT as usize]; display::HIGH_WIDTH as usize];
+        for column in display.iter_mut() {
+            for pixel in column.iter_mut() {
+                *pixel = *bytes.get(offset)? != 0;
+                offset += 1;
+            }
+        }
+
+        let resolution = match *bytes.get(offset)? {
+            0 => Resolution::Low,
+            1 => Resolution::High,
+            _ => return None,
+        };
+        offset += 1;
+
+        let pc = u16::from_le_bytes(bytes.get(offset..offset + 2)?.try_into().ok()?);
+        offset += 2;
+        let i = u16::from_le_bytes(bytes.get(offset..offset + 2)?.try_into().ok()?);
+        offset += 2;
+
+        let mut stack = [0u16; 16];
+        for addr in stack.iter_mut() {
+            *addr = u16::from_le_bytes(bytes.get(offset..offset + 2)?.try_into().ok()?);
+            offset += 2;
+        }
+
+        let sp = *bytes.get(offset)?;
+        offset += 1;
+        let delay_timer = *bytes.get(offset)?;
+        offset += 1;
+        let sound_timer = *bytes.get(offset)?;
+        offset += 1;
+
+        let v: [u8; 16] = bytes.get(offset..offset + 16)?.try_into().ok()?;
+        offset += 16;
+
+        let rpl: [u8; 8] = bytes.get(offset..offset + 8)?.try_into().ok()?;
+
+        Some(Chip8State {
+            memory,
+            display,
+            resolution,
+            pc,
+            i,
+            stack,
+            sp,
+            delay_timer,
+            sound_timer,
+            v,
+            rpl,
+        })
+    }
+
+    pub fn save_to_file(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.to_bytes())
+    }
+
+    pub fn load_from_file(path: &Path) -> io::Result<Chip8State> {
+        let bytes = fs::read(path)?;
+        Chip8State::from_bytes(&bytes)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "corrupt save state"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Chip8State {
+        let mut state = Chip8State {
+            memory: [0; MEMORY_SIZE],
+            display: [[false; display::HIGH_HEIGHT as usize]; display::HIGH_WIDTH as usize],
+            resolution: Resolution::High,
+            pc: 0x250,
+            i: 0x0ABC,
+            stack: [0; 16],
+            sp: 3,
+            delay_timer: 10,
+            sound_timer: 20,
+            v: [0; 16],
+            rpl: [0; 8],
+        };
+        state.memory[0] = 0xAB;
+        state.display[1][2] = true;
+        state.stack[0] = 0x300;
+        state.v[5] = 42;
+        state.rpl[3] = 7;
+        state
+    }
+
+    #[test]
+    fn round_trips_through_bytes() {
+        let state = sample();
+        assert_eq!(Chip8State::from_bytes(&state.to_bytes()), Some(state));
+    }
+
+    #[test]
+    fn from_bytes_rejects_truncated_input() {
+        assert_eq!(Chip8State::from_bytes(&[0u8; 4]), None);
+    }
+}
@@ -0,0 +1,290 @@
+use std::collections::HashSet;
+use std::io::{self, Write};
+
+use crate::chip8::Chip8;
+
+/// What made the debugger stop and hand control back to the user.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StopReason {
+    Breakpoint(u16),
+    IChanged,
+    RegisterChanged(usize),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Command {
+    Step,
+    Continue,
+    Break(u16),
+    ClearBreak(u16),
+    WatchI,
+    WatchRegister(usize),
+    DumpMemory(u16, u16),
+    DumpStack,
+    DumpRegisters,
+}
+
+/// A command-driven monitor for single-stepping a [`Chip8`], inspired by the
+/// classic break/watch/dump monitors shipped with early microcomputers.
+pub struct Debugger {
+    breakpoints: HashSet<u16>,
+    watched_registers: HashSet<usize>,
+    watch_i: bool,
+    last_i: u16,
+    last_command: Option<Command>,
+}
+
+impl Debugger {
+    pub fn new(chip8: &Chip8) -> Debugger {
+        Debugger {
+            breakpoints: HashSet::new(),
+            watched_registers: HashSet::new(),
+            watch_i: false,
+            last_i: chip8.i(),
+            last_command: None,
+        }
+    }
+
+    pub fn break_at(&mut self, addr: u16) {
+        self.breakpoints.insert(addr);
+    }
+
+    /// Advances `chip8` by one instruction unless it sits on a breakpoint.
+    /// The REPL takes over when a breakpoint is hit (before the instruction
+    /// runs) or when the instruction changes a watched register or `I`
+    /// (after it runs). Call this once per tick of the host loop.
+    pub fn tick(&mut self, chip8: &mut Chip8) {
+        if let Some(reason) = self.check_breakpoint(chip8) {
+            self.announce(reason);
+            self.repl(chip8);
+            return;
+        }
+
+        if let Some(reason) = self.step(chip8) {
+            self.announce(reason);
+            self.repl(chip8);
+        }
+    }
+
+    /// Checks whether `chip8` is sitting on a breakpoint address, without
+    /// advancing it. Must run before [`Debugger::step`], since a breakpoint
+    /// stops execution before the instruction at its address runs.
+    fn check_breakpoint(&self, chip8: &Chip8) -> Option<StopReason> {
+        self.breakpoints
+            .contains(&chip8.pc())
+            .then(|| StopReason::Breakpoint(chip8.pc()))
+    }
+
+    fn announce(&self, reason: StopReason) {
+        match reason {
+            StopReason::Breakpoint(addr) => println!("breakpoint hit at {addr:#06X}"),
+            StopReason::IChanged => println!("I changed to {:#06X}", self.last_i),
+            StopReason::RegisterChanged(reg) => println!("V{reg:X} changed"),
+        }
+    }
+
+    /// Advances `chip8` by one instruction, returning the reason if a
+    /// watched register or `I` changed as a result. Breakpoints are not
+    /// checked here; call [`Debugger::check_breakpoint`] beforehand.
+    fn step(&mut self, chip8: &mut Chip8) -> Option<StopReason> {
+        let pre_i = chip8.i();
+        let pre_v = *chip8.v();
+
+        chip8.tick();
+
+        self.last_i = chip8.i();
+        let post_v = *chip8.v();
+
+        if self.watch_i && self.last_i != pre_i {
+            return Some(StopReason::IChanged);
+        }
+        self.watched_registers
+            .iter()
+            .find(|&&reg| post_v[reg] != pre_v[reg])
+            .map(|&reg| StopReason::RegisterChanged(reg))
+    }
+
+    fn repl(&mut self, chip8: &mut Chip8) {
+        loop {
+            print!("({:#06X}) > ", chip8.pc());
+            io::stdout().flush().ok();
+
+            let mut line = String::new();
+            if io::stdin().read_line(&mut line).unwrap_or(0) == 0 {
+                return;
+            }
+            let line = line.trim();
+
+            let (command, repeat) = match self.parse(line) {
+                Some(parsed) => parsed,
+                None => {
+                    println!("unrecognised command: {line}");
+                    continue;
+                }
+            };
+
+            for _ in 0..repeat {
+                if self.run(command, chip8) {
+                    return;
+                }
+            }
+            self.last_command = Some(command);
+        }
+    }
+
+    /// Parses a command line, falling back to the last command when given a
+    /// bare repeat count (e.g. `5` re-runs the last command five times).
+    fn parse(&self, line: &str) -> Option<(Command, u32)> {
+        if let Ok(count) = line.parse::<u32>() {
+            return self.last_command.map(|command| (command, count));
+        }
+
+        let mut parts = line.split_whitespace();
+        let command = match parts.next()? {
+            "s" | "step" => Command::Step,
+            "c" | "continue" => Command::Continue,
+            "b" | "break" => Command::Break(parse_addr(parts.next()?)?),
+            "cb" | "clear" => Command::ClearBreak(parse_addr(parts.next()?)?),
+            "wi" => Command::WatchI,
+            "wr" => Command::WatchRegister(parse_addr(parts.next()?)? as usize),
+            "mem" | "dump" => {
+                let start = parse_addr(parts.next()?)?;
+                let len = parts.next().and_then(|n| n.parse().ok()).unwrap_or(16);
+                Command::DumpMemory(start, len)
+            }
+            "stack" => Command::DumpStack,
+            "regs" => Command::DumpRegisters,
+            _ => return None,
+        };
+
+        Some((command, 1))
+    }
+
+    /// Runs a single command, returning `true` if the REPL should exit back
+    /// to the host's tick loop.
+    fn run(&mut self, command: Command, chip8: &mut Chip8) -> bool {
+        match command {
+            Command::Step => {
+                self.step(chip8);
+                println!("{chip8:?}");
+                false
+            }
+            Command::Continue => {
+                // Step once first so continuing from a breakpoint moves past it.
+                if self.step(chip8).is_none() {
+                    loop {
+                        if self.check_breakpoint(chip8).is_some() || self.step(chip8).is_some() {
+                            break;
+                        }
+                    }
+                }
+                true
+            }
+            Command::Break(addr) => {
+                self.break_at(addr);
+                println!("breakpoint set at {addr:#06X}");
+                false
+            }
+            Command::ClearBreak(addr) => {
+                self.breakpoints.remove(&addr);
+                println!("breakpoint cleared at {addr:#06X}");
+                false
+            }
+            Command::WatchI => {
+                self.watch_i = true;
+                false
+            }
+            Command::WatchRegister(reg) => {
+                if reg < chip8.v().len() {
+                    self.watched_registers.insert(reg);
+                } else {
+                    println!("no such register: V{reg:X}");
+                }
+                false
+            }
+            Command::DumpMemory(start, len) => {
+                self.dump_memory(chip8, start, len);
+                false
+            }
+            Command::DumpStack => {
+                println!("sp: {:#04X}", chip8.sp());
+                println!("{:#06X?}", chip8.stack());
+                false
+            }
+            Command::DumpRegisters => {
+                println!("{chip8:?}");
+                false
+            }
+        }
+    }
+
+    fn dump_memory(&self, chip8: &Chip8, start: u16, len: u16) {
+        let memory = chip8.memory();
+        let start = (start as usize).min(memory.len());
+        let end = (start + len as usize).min(memory.len());
+        for (offset, chunk) in memory[start..end].chunks(8).enumerate() {
+            let addr = start + offset * 8;
+            let bytes = chunk
+                .iter()
+                .map(|b| format!("{b:02X}"))
+                .collect::<Vec<_>>()
+                .join(" ");
+            println!("{addr:#06X}: {bytes}");
+        }
+    }
+}
+
+pub(crate) fn parse_addr(s: &str) -> Option<u16> {
+    let s = s.trim_start_matches("0x").trim_start_matches("0X");
+    u16::from_str_radix(s, 16).ok().or_else(|| s.parse().ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+    use crate::chip8::quirks::Quirks;
+
+    static ROM_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+    fn chip8_with_rom(bytes: &[u8]) -> Chip8 {
+        let id = ROM_COUNTER.fetch_add(1, Ordering::Relaxed);
+        let path = std::env::temp_dir().join(format!("chip8-debugger-test-{id}.ch8"));
+        fs::write(&path, bytes).unwrap();
+
+        let mut chip8 = Chip8::with_quirks(Quirks::chip8());
+        chip8.load_rom(&path).unwrap();
+        fs::remove_file(&path).ok();
+        chip8
+    }
+
+    #[test]
+    fn watch_register_fires_on_the_tick_that_changes_it() {
+        // LD V0, 0x05 ; JP 0x202 (infinite loop, so the watch is what stops us)
+        let mut chip8 = chip8_with_rom(&[0x60, 0x05, 0x12, 0x02]);
+        let mut debugger = Debugger::new(&chip8);
+        debugger.watched_registers.insert(0);
+
+        assert_eq!(debugger.step(&mut chip8), Some(StopReason::RegisterChanged(0)));
+    }
+
+    #[test]
+    fn watch_i_fires_on_the_tick_that_changes_it() {
+        // LD I, 0x300 ; JP 0x202 (infinite loop, so the watch is what stops us)
+        let mut chip8 = chip8_with_rom(&[0xA3, 0x00, 0x12, 0x02]);
+        let mut debugger = Debugger::new(&chip8);
+        debugger.watch_i = true;
+
+        assert_eq!(debugger.step(&mut chip8), Some(StopReason::IChanged));
+    }
+
+    #[test]
+    fn step_does_not_fire_unwatched_changes() {
+        let mut chip8 = chip8_with_rom(&[0x60, 0x05, 0x12, 0x02]);
+        let mut debugger = Debugger::new(&chip8);
+
+        assert_eq!(debugger.step(&mut chip8), None);
+    }
+}
@@ -1,32 +1,83 @@
-use std::path::PathBuf;
+use std::fs;
+use std::path::{Path, PathBuf};
 
 use chip8::{
     keypad::{Key, KeyState},
+    quirks::Quirks,
+    state::Chip8State,
     Chip8,
 };
-use clap::{command, Parser};
+use clap::{Parser, ValueEnum};
 use macroquad::{
     audio::{self, PlaySoundParams},
     color::{BLACK, WHITE},
     input::KeyCode,
     shapes::draw_rectangle,
-    time,
     window::{next_frame, request_new_screen_size},
 };
 use strum::IntoEnumIterator;
-use tracing::info;
-use tracing::{debug, Level};
+use tracing::{debug, error, info, Level};
+
+use debugger::Debugger;
+use tone::{generate_tone_wav, ToneConfig};
 
 mod chip8;
+mod debugger;
+mod tone;
 
 const SCALE: f32 = 15.0;
 const PIXEL_COLOR: macroquad::color::Color = WHITE;
 const TICKS_PER_SECOND: u16 = 700;
+const SAVE_SLOTS: usize = 8;
 
 #[derive(Parser, Debug)]
 #[command(version, about, long_about = None)]
 struct Args {
     rom: PathBuf,
+
+    /// Compatibility profile to use for opcodes with ambiguous behaviour.
+    #[arg(long, value_enum, default_value_t = QuirksProfile::Chip8)]
+    quirks: QuirksProfile,
+
+    /// Route each tick through the interactive debugger.
+    #[arg(long)]
+    debug: bool,
+
+    /// Address to break at when using --debug, in hex (a `0x` prefix is
+    /// optional). May be
+    /// repeated. Defaults to the ROM's entry point if --debug is set with
+    /// no breakpoints, so the debugger always has somewhere to stop.
+    #[arg(long = "break", value_name = "ADDR")]
+    breakpoints: Vec<String>,
+
+    /// Print a disassembly listing of the ROM and exit, instead of running it.
+    #[arg(long)]
+    disassemble: bool,
+
+    /// Print a live disassembly trace of every executed instruction.
+    #[arg(long)]
+    trace: bool,
+
+    /// Frequency, in Hz, of the synthesized beep tone.
+    #[arg(long, default_value_t = ToneConfig::default().frequency_hz)]
+    tone_hz: f32,
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy)]
+enum QuirksProfile {
+    Chip8,
+    Superchip,
+    Xochip,
+}
+
+impl From<QuirksProfile> for Quirks {
+    fn from(value: QuirksProfile) -> Self {
+        match value {
+            QuirksProfile::Chip8 => Quirks::chip8(),
+            QuirksProfile::Superchip => Quirks::superchip(),
+            QuirksProfile::Xochip => Quirks::xochip(),
+        }
+    }
 }
 
 #[macroquad::main("Chip-8")]
@@ -39,27 +90,63 @@ async fn main() {
 
     let args = Args::parse();
 
-    let mut chip8 = Chip8::new();
+    if args.disassemble {
+        let listing = chip8::disasm::disassemble_rom(&args.rom).expect("error reading rom");
+        for (addr, mnemonic) in listing {
+            println!("{addr:#06X}  {mnemonic}");
+        }
+        return;
+    }
+
+    let mut chip8 = Chip8::with_quirks(args.quirks.into());
     chip8.load_rom(&args.rom).expect("error loading rom");
     info!("Loaded ROM {rom}", rom = args.rom.display());
 
-    let beep = audio::load_sound("beep.wav")
+    let tone = generate_tone_wav(&ToneConfig {
+        frequency_hz: args.tone_hz,
+        ..ToneConfig::default()
+    });
+    let beep = audio::load_sound_from_bytes(&tone)
         .await
-        .expect("error loading sound");
+        .expect("error generating tone");
+
+    request_new_screen_size(chip8.width() as f32 * SCALE, chip8.height() as f32 * SCALE);
 
-    request_new_screen_size(
-        chip8::display::WIDTH as f32 * SCALE,
-        chip8::display::HEIGHT as f32 * SCALE,
-    );
+    let mut debugger = args.debug.then(|| {
+        let mut debugger = Debugger::new(&chip8);
+        let addrs: Vec<u16> = args
+            .breakpoints
+            .iter()
+            .filter_map(|s| debugger::parse_addr(s))
+            .collect();
+        if addrs.is_empty() {
+            debugger.break_at(chip8.pc());
+        } else {
+            for addr in addrs {
+                debugger.break_at(addr);
+            }
+        }
+        debugger
+    });
 
-    let mut ticks: u128 = 0;
     loop {
+        if chip8.exited {
+            break;
+        }
+
+        handle_save_state_hotkeys(&mut chip8, &args.rom);
+
         for _ in 0..TICKS_PER_SECOND {
             update_keypad(&mut chip8);
 
-            // debug!(ticks, fps = time::get_fps(), ?chip8);
-            chip8.tick();
-            ticks += 1;
+            if args.trace {
+                debug!(pc = %format!("{:#06X}", chip8.pc()), instr = %chip8.disassemble_current());
+            }
+
+            match &mut debugger {
+                Some(debugger) => debugger.tick(&mut chip8),
+                None => chip8.tick(),
+            }
         }
 
         draw_display(&chip8);
@@ -81,8 +168,10 @@ async fn main() {
 }
 
 fn draw_display(chip8: &Chip8) {
-    for y in 0..chip8::display::HEIGHT {
-        for x in 0..chip8::display::WIDTH {
+    request_new_screen_size(chip8.width() as f32 * SCALE, chip8.height() as f32 * SCALE);
+
+    for y in 0..chip8.height() {
+        for x in 0..chip8.width() {
             let is_pixel_on = chip8.display[x as usize][y as usize];
             let colour = if is_pixel_on { PIXEL_COLOR } else { BLACK };
             draw_pixel(x, y, colour);
@@ -96,6 +185,73 @@ fn draw_pixel(x: u8, y: u8, color: macroquad::color::Color) {
     draw_rectangle(x, y, SCALE, SCALE, color);
 }
 
+/// Saves to / restores from numbered slots named after the loaded ROM, e.g.
+/// `mygame-0.state`. F1-F8 save to the matching slot; F9 restores whichever
+/// slot was most recently written, regardless of its number.
+fn handle_save_state_hotkeys(chip8: &mut Chip8, rom: &Path) {
+    for slot in 0..SAVE_SLOTS {
+        if macroquad::input::is_key_pressed(save_slot_keycode(slot)) {
+            let path = slot_path(rom, slot);
+            match chip8.save_state().save_to_file(&path) {
+                Ok(()) => info!("saved state to {}", path.display()),
+                Err(err) => error!("error saving state to {}: {err}", path.display()),
+            }
+        }
+    }
+
+    if macroquad::input::is_key_pressed(KeyCode::F9) {
+        match most_recent_save(rom) {
+            Some(path) => match Chip8State::load_from_file(&path) {
+                Ok(state) => {
+                    chip8.load_state(&state);
+                    info!("loaded state from {}", path.display());
+                }
+                Err(err) => error!("error loading state from {}: {err}", path.display()),
+            },
+            None => info!("no save states found for {}", rom.display()),
+        }
+    }
+}
+
+fn save_slot_keycode(slot: usize) -> KeyCode {
+    match slot {
+        0 => KeyCode::F1,
+        1 => KeyCode::F2,
+        2 => KeyCode::F3,
+        3 => KeyCode::F4,
+        4 => KeyCode::F5,
+        5 => KeyCode::F6,
+        6 => KeyCode::F7,
+        _ => KeyCode::F8,
+    }
+}
+
+fn slot_path(rom: &Path, slot: usize) -> PathBuf {
+    let stem = rom.file_stem().and_then(|s| s.to_str()).unwrap_or("rom");
+    rom.with_file_name(format!("{stem}-{slot}.state"))
+}
+
+/// Finds the most recently modified save state for `rom`, regardless of
+/// which numbered slot it was written to.
+fn most_recent_save(rom: &Path) -> Option<PathBuf> {
+    let stem = rom.file_stem()?.to_str()?.to_string();
+    let dir = match rom.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+    let prefix = format!("{stem}-");
+
+    fs::read_dir(dir)
+        .ok()?
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| {
+            entry.path().extension().is_some_and(|ext| ext == "state")
+                && entry.file_name().to_string_lossy().starts_with(&prefix)
+        })
+        .max_by_key(|entry| entry.metadata().and_then(|m| m.modified()).ok())
+        .map(|entry| entry.path())
+}
+
 fn update_keypad(chip8: &mut Chip8) {
     for key in Key::iter() {
         if macroquad::input::is_key_down(key.into()) {
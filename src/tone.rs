@@ -0,0 +1,113 @@
+/// Parameters for the synthesized beep, kept separate from any one playback
+/// so a future XO-CHIP pitch register (`FX3A`) can retune it at runtime.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ToneConfig {
+    pub frequency_hz: f32,
+    pub sample_rate: u32,
+    pub duration_ms: u32,
+}
+
+impl Default for ToneConfig {
+    fn default() -> Self {
+        ToneConfig {
+            frequency_hz: 440.0,
+            sample_rate: 44_100,
+            duration_ms: 150,
+        }
+    }
+}
+
+/// Synthesizes a short square-wave tone and encodes it as a 16-bit mono WAV
+/// byte buffer, ready for `macroquad::audio::load_sound_from_bytes`. A
+/// linear attack/release envelope is applied so the tone doesn't click or
+/// ring when it starts and stops abruptly.
+pub fn generate_tone_wav(config: &ToneConfig) -> Vec<u8> {
+    let sample_count = (config.sample_rate as u64 * config.duration_ms as u64 / 1000) as usize;
+    let envelope_samples = (config.sample_rate / 100).max(1) as usize; // ~10ms ramp
+
+    let mut samples = Vec::with_capacity(sample_count);
+    for n in 0..sample_count {
+        let t = n as f32 / config.sample_rate as f32;
+        let phase = (t * config.frequency_hz).fract();
+        let square = if phase < 0.5 { 1.0 } else { -1.0 };
+
+        let attack = n as f32 / envelope_samples as f32;
+        let release = (sample_count - n) as f32 / envelope_samples as f32;
+        let envelope = attack.min(release).clamp(0.0, 1.0);
+
+        samples.push((square * envelope * i16::MAX as f32) as i16);
+    }
+
+    encode_wav(&samples, config.sample_rate)
+}
+
+fn encode_wav(samples: &[i16], sample_rate: u32) -> Vec<u8> {
+    const CHANNELS: u16 = 1;
+    const BITS_PER_SAMPLE: u16 = 16;
+
+    let byte_rate = sample_rate * CHANNELS as u32 * BITS_PER_SAMPLE as u32 / 8;
+    let block_align = CHANNELS * BITS_PER_SAMPLE / 8;
+    let data_len = samples.len() as u32 * 2;
+
+    let mut buf = Vec::with_capacity(44 + data_len as usize);
+    buf.extend_from_slice(b"RIFF");
+    buf.extend_from_slice(&(36 + data_len).to_le_bytes());
+    buf.extend_from_slice(b"WAVE");
+    buf.extend_from_slice(b"fmt ");
+    buf.extend_from_slice(&16u32.to_le_bytes()); // fmt chunk size
+    buf.extend_from_slice(&1u16.to_le_bytes()); // PCM
+    buf.extend_from_slice(&CHANNELS.to_le_bytes());
+    buf.extend_from_slice(&sample_rate.to_le_bytes());
+    buf.extend_from_slice(&byte_rate.to_le_bytes());
+    buf.extend_from_slice(&block_align.to_le_bytes());
+    buf.extend_from_slice(&BITS_PER_SAMPLE.to_le_bytes());
+    buf.extend_from_slice(b"data");
+    buf.extend_from_slice(&data_len.to_le_bytes());
+    for sample in samples {
+        buf.extend_from_slice(&sample.to_le_bytes());
+    }
+
+    buf
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn produces_a_well_formed_wav_header() {
+        let config = ToneConfig {
+            frequency_hz: 440.0,
+            sample_rate: 44_100,
+            duration_ms: 10,
+        };
+        let wav = generate_tone_wav(&config);
+
+        assert_eq!(&wav[0..4], b"RIFF");
+        assert_eq!(&wav[8..12], b"WAVE");
+        assert_eq!(&wav[12..16], b"fmt ");
+        assert_eq!(&wav[36..40], b"data");
+
+        let sample_count = (config.sample_rate as u64 * config.duration_ms as u64 / 1000) as usize;
+        let data_len = u32::from_le_bytes(wav[40..44].try_into().unwrap());
+        assert_eq!(data_len as usize, sample_count * 2);
+        assert_eq!(wav.len(), 44 + sample_count * 2);
+    }
+
+    #[test]
+    fn envelope_ramps_up_from_silence() {
+        let config = ToneConfig {
+            frequency_hz: 440.0,
+            sample_rate: 44_100,
+            duration_ms: 10,
+        };
+        let wav = generate_tone_wav(&config);
+        let data = &wav[44..];
+
+        let first_sample = i16::from_le_bytes(data[0..2].try_into().unwrap());
+        let mid = data.len() / 2 - (data.len() / 2) % 2;
+        let mid_sample = i16::from_le_bytes(data[mid..mid + 2].try_into().unwrap());
+        assert_eq!(first_sample, 0);
+        assert!(mid_sample.unsigned_abs() > 1000);
+    }
+}